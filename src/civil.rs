@@ -0,0 +1,155 @@
+use core::time::Duration;
+
+use crate::SystemTime;
+
+/// A civil (proleptic Gregorian) calendar date and time of day, in UTC.
+///
+/// Produced by [`SystemTime::to_civil_utc`] using Howard Hinnant's
+/// `civil_from_days`/`days_from_civil` algorithm, which is branch-light and
+/// allocation-free. Since `SystemTime` stores an unsigned duration since the
+/// Unix epoch, only dates at or after 1970-01-01 are representable;
+/// [`SystemTime::from_civil_utc`] panics if given an earlier date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDateTime {
+    /// Proleptic Gregorian calendar year.
+    pub year: i64,
+    /// Month of the year, `1..=12`.
+    pub month: u32,
+    /// Day of the month, `1..=31`.
+    pub day: u32,
+    /// Hour of the day, `0..=23`.
+    pub hour: u32,
+    /// Minute of the hour, `0..=59`.
+    pub minute: u32,
+    /// Second of the minute, `0..=59`.
+    pub second: u32,
+    /// Nanosecond of the second, `0..=999_999_999`.
+    pub nanos: u32,
+}
+
+impl SystemTime {
+    /// Breaks this timestamp down into a civil UTC date and time.
+    pub const fn to_civil_utc(self) -> CivilDateTime {
+        let since_unix_epoch = self.as_unix_duration();
+        let secs = since_unix_epoch.as_secs() as i64;
+
+        let days = secs.div_euclid(86400);
+        let rem = secs.rem_euclid(86400);
+
+        let hour = (rem / 3600) as u32;
+        let minute = (rem % 3600 / 60) as u32;
+        let second = (rem % 60) as u32;
+
+        let z = days + 719468;
+        let era = if z >= 0 { z } else { z - 146096 } / 146097;
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let y = yoe + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = y + if month <= 2 { 1 } else { 0 };
+
+        CivilDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanos: since_unix_epoch.subsec_nanos(),
+        }
+    }
+
+    /// Builds a `SystemTime` from a civil UTC date and time, the inverse of
+    /// [`SystemTime::to_civil_utc`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `civil` is earlier than 1970-01-01T00:00:00Z, since
+    /// `SystemTime` cannot represent an instant before the Unix epoch.
+    pub const fn from_civil_utc(civil: CivilDateTime) -> SystemTime {
+        let y = civil.year - if civil.month <= 2 { 1 } else { 0 };
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let mp = if civil.month > 2 {
+            civil.month - 3
+        } else {
+            civil.month + 9
+        } as i64;
+        let doy = (153 * mp + 2) / 5 + civil.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146097 + doe - 719468;
+
+        let day_secs = civil.hour as i64 * 3600 + civil.minute as i64 * 60 + civil.second as i64;
+        let secs = days * 86400 + day_secs;
+
+        assert!(
+            secs >= 0,
+            "CivilDateTime predates the Unix epoch; SystemTime cannot represent it"
+        );
+
+        SystemTime::from_unix_duration(Duration::new(secs as u64, civil.nanos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unix_epoch_breaks_down_to_1970_01_01() {
+        let civil = crate::UNIX_EPOCH.to_civil_utc();
+        assert_eq!(civil.year, 1970);
+        assert_eq!(civil.month, 1);
+        assert_eq!(civil.day, 1);
+        assert_eq!(civil.hour, 0);
+        assert_eq!(civil.minute, 0);
+        assert_eq!(civil.second, 0);
+    }
+
+    #[test]
+    fn known_timestamp_breaks_down_correctly() {
+        // 2024-01-15T12:34:56Z
+        let time = SystemTime::from_unix_duration(Duration::from_secs(1_705_322_096));
+        let civil = time.to_civil_utc();
+        assert_eq!(civil.year, 2024);
+        assert_eq!(civil.month, 1);
+        assert_eq!(civil.day, 15);
+        assert_eq!(civil.hour, 12);
+        assert_eq!(civil.minute, 34);
+        assert_eq!(civil.second, 56);
+    }
+
+    #[test]
+    fn roundtrips_through_civil_and_back() {
+        let time = SystemTime::from_unix_duration(Duration::new(1_705_322_096, 123_456_789));
+        let civil = time.to_civil_utc();
+        assert_eq!(SystemTime::from_civil_utc(civil), time);
+    }
+
+    #[test]
+    fn roundtrips_across_a_leap_day() {
+        // 2024-02-29T00:00:00Z
+        let time = SystemTime::from_unix_duration(Duration::from_secs(1_709_164_800));
+        let civil = time.to_civil_utc();
+        assert_eq!(civil.month, 2);
+        assert_eq!(civil.day, 29);
+        assert_eq!(SystemTime::from_civil_utc(civil), time);
+    }
+
+    #[test]
+    #[should_panic(expected = "CivilDateTime predates the Unix epoch")]
+    fn from_civil_utc_panics_before_unix_epoch() {
+        let _ = SystemTime::from_civil_utc(CivilDateTime {
+            year: 1969,
+            month: 12,
+            day: 31,
+            hour: 23,
+            minute: 59,
+            second: 59,
+            nanos: 0,
+        });
+    }
+}
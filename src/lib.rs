@@ -5,6 +5,16 @@
 
 pub use core::time::Duration;
 
+mod civil;
+
+#[cfg(any(
+    not(feature = "std"),
+    all(feature = "std", target_family = "wasm", target_os = "unknown"),
+    feature = "async",
+    feature = "alloc"
+))]
+mod once_static;
+
 #[cfg(any(
     not(feature = "std"),
     all(feature = "std", target_family = "wasm", target_os = "unknown")
@@ -20,6 +30,35 @@ mod global;
 mod instant;
 mod system;
 
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+mod timer;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod timer_context;
+
+#[cfg(all(
+    target_has_atomic = "64",
+    any(
+        not(feature = "std"),
+        all(feature = "std", target_family = "wasm", target_os = "unknown")
+    )
+))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(
+        target_has_atomic = "64",
+        any(
+            not(feature = "std"),
+            all(feature = "std", target_family = "wasm", target_os = "unknown")
+        )
+    )))
+)]
+mod monotonic_guard;
+
+pub use self::civil::*;
+
 #[cfg(any(
     not(feature = "std"),
     all(feature = "std", target_family = "wasm", target_os = "unknown")
@@ -34,3 +73,30 @@ mod system;
 pub use self::global::*;
 pub use self::instant::*;
 pub use self::system::*;
+
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use self::timer::*;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::timer_context::*;
+
+#[cfg(all(
+    target_has_atomic = "64",
+    any(
+        not(feature = "std"),
+        all(feature = "std", target_family = "wasm", target_os = "unknown")
+    )
+))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(
+        target_has_atomic = "64",
+        any(
+            not(feature = "std"),
+            all(feature = "std", target_family = "wasm", target_os = "unknown")
+        )
+    )))
+)]
+pub use self::monotonic_guard::*;
@@ -0,0 +1,110 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+
+use crate::{Instant, MonotonicClock, SystemTime, WallClock};
+
+/// Wraps a [`MonotonicClock`] and clamps its readings so [`Instant::now`]
+/// never regresses, even if the underlying provider is buggy or its counter
+/// wraps.
+///
+/// Each reading is compared against the highest tick value seen so far
+/// (stored in an `AtomicU64`) and clamped up to it via a compare-exchange
+/// loop, guaranteeing the monotonicity invariant `duration_since`/`elapsed`
+/// rely on regardless of backend quality.
+pub struct MonotonicGuard<C> {
+    inner: C,
+    last_seen_nanos: AtomicU64,
+}
+
+impl<C> MonotonicGuard<C> {
+    /// Wraps `inner`, starting the clamp floor at zero.
+    #[inline]
+    pub const fn new(inner: C) -> Self {
+        Self {
+            inner,
+            last_seen_nanos: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for MonotonicGuard<C> {
+    fn instant(&self) -> Option<Instant> {
+        let raw_nanos = self.inner.instant()?.to_ticks().as_nanos().min(u64::MAX as u128) as u64;
+
+        let mut last = self.last_seen_nanos.load(Ordering::Relaxed);
+        loop {
+            if raw_nanos <= last {
+                return Some(Instant::from_ticks(Duration::from_nanos(last)));
+            }
+
+            match self.last_seen_nanos.compare_exchange_weak(
+                last,
+                raw_nanos,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Some(Instant::from_ticks(Duration::from_nanos(raw_nanos))),
+                Err(observed) => last = observed,
+            }
+        }
+    }
+}
+
+impl<C: WallClock> WallClock for MonotonicGuard<C> {
+    fn system_time(&self) -> Option<SystemTime> {
+        self.inner.system_time()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StepClock {
+        ticks: core::cell::Cell<u64>,
+    }
+
+    impl MonotonicClock for StepClock {
+        fn instant(&self) -> Option<Instant> {
+            Some(Instant::from_ticks(Duration::from_nanos(self.ticks.get())))
+        }
+    }
+
+    #[test]
+    fn passes_through_increasing_readings() {
+        let clock = StepClock {
+            ticks: core::cell::Cell::new(10),
+        };
+        let guard = MonotonicGuard::new(clock);
+
+        assert_eq!(
+            guard.instant().unwrap().to_ticks(),
+            Duration::from_nanos(10)
+        );
+
+        guard.inner.ticks.set(20);
+        assert_eq!(
+            guard.instant().unwrap().to_ticks(),
+            Duration::from_nanos(20)
+        );
+    }
+
+    #[test]
+    fn clamps_a_regressing_reading_to_the_last_seen_value() {
+        let clock = StepClock {
+            ticks: core::cell::Cell::new(100),
+        };
+        let guard = MonotonicGuard::new(clock);
+
+        assert_eq!(
+            guard.instant().unwrap().to_ticks(),
+            Duration::from_nanos(100)
+        );
+
+        guard.inner.ticks.set(50);
+        assert_eq!(
+            guard.instant().unwrap().to_ticks(),
+            Duration::from_nanos(100)
+        );
+    }
+}
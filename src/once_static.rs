@@ -0,0 +1,134 @@
+//! Shared "set at most once" cell backing this crate's
+//! `set_global_*`/`global_*` installer pairs (time context, alarm driver,
+//! timer driver), so each one doesn't hand-roll its own OnceLock/atomic
+//! state machine. It is safe to use from any thread, except on targets
+//! without 8-bit atomics, where the cell is unsynchronized.
+
+#[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+use core::cell::UnsafeCell;
+#[cfg(all(not(feature = "std"), not(target_has_atomic = "8")))]
+use core::cell::UnsafeCell;
+#[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+const UNINITIALIZED: u8 = 0;
+#[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+const INITIALIZING: u8 = 1;
+#[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+const READY: u8 = 2;
+
+pub(crate) struct OnceStatic<T: Copy> {
+    #[cfg(feature = "std")]
+    cell: std::sync::OnceLock<T>,
+
+    #[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+    state: AtomicU8,
+    #[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+    value: UnsafeCell<Option<T>>,
+
+    #[cfg(all(not(feature = "std"), not(target_has_atomic = "8")))]
+    value: UnsafeCell<Option<T>>,
+}
+
+#[cfg(not(feature = "std"))]
+unsafe impl<T: Copy> Sync for OnceStatic<T> {}
+
+impl<T: Copy> OnceStatic<T> {
+    #[cfg(feature = "std")]
+    pub(crate) const fn new() -> Self {
+        Self {
+            cell: std::sync::OnceLock::new(),
+        }
+    }
+
+    #[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+    pub(crate) const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNINITIALIZED),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    #[cfg(all(not(feature = "std"), not(target_has_atomic = "8")))]
+    pub(crate) const fn new() -> Self {
+        Self {
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    /// Sets the value, returning `true` if this call won the race.
+    pub(crate) fn set(&self, value: T) -> bool {
+        #[cfg(feature = "std")]
+        {
+            self.cell.set(value).is_ok()
+        }
+
+        #[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+        {
+            match self.state.compare_exchange(
+                UNINITIALIZED,
+                INITIALIZING,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    unsafe {
+                        *self.value.get() = Some(value);
+                    }
+                    self.state.store(READY, Ordering::Release);
+                    true
+                }
+                Err(_) => {
+                    while self.state.load(Ordering::Acquire) == INITIALIZING {
+                        core::hint::spin_loop();
+                    }
+                    false
+                }
+            }
+        }
+
+        #[cfg(all(not(feature = "std"), not(target_has_atomic = "8")))]
+        {
+            // Fallback for targets without atomics. Call during single-threaded
+            // startup before concurrency begins.
+            unsafe {
+                if (*self.value.get()).is_some() {
+                    false
+                } else {
+                    *self.value.get() = Some(value);
+                    true
+                }
+            }
+        }
+    }
+
+    /// Returns the value if it has been set.
+    pub(crate) fn get(&self) -> Option<T> {
+        #[cfg(feature = "std")]
+        {
+            self.cell.get().copied()
+        }
+
+        #[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+        {
+            let mut state = self.state.load(Ordering::Acquire);
+            while state == INITIALIZING {
+                core::hint::spin_loop();
+                state = self.state.load(Ordering::Acquire);
+            }
+
+            if state == READY {
+                unsafe { *self.value.get() }
+            } else {
+                None
+            }
+        }
+
+        #[cfg(all(not(feature = "std"), not(target_has_atomic = "8")))]
+        {
+            // Fallback for targets without atomics. See synchronization note in `set`.
+            unsafe { *self.value.get() }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use core::ops::{Add, Sub};
 use core::time::Duration;
 
 /// Wall clock time represented as a duration since the Unix epoch.
@@ -61,6 +62,48 @@ impl SystemTime {
             Err(earlier.since_unix_epoch - self.since_unix_epoch)
         }
     }
+
+    /// Returns `Some(time)` if adding the duration does not overflow.
+    #[inline]
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.since_unix_epoch
+            .checked_add(duration)
+            .map(Self::from_unix_duration)
+    }
+
+    /// Returns `Some(time)` if subtracting the duration does not underflow.
+    #[inline]
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.since_unix_epoch
+            .checked_sub(duration)
+            .map(Self::from_unix_duration)
+    }
+}
+
+impl Add<Duration> for SystemTime {
+    type Output = SystemTime;
+
+    fn add(self, other: Duration) -> SystemTime {
+        self.checked_add(other)
+            .expect("overflow while adding Duration to SystemTime")
+    }
+}
+
+impl Sub<Duration> for SystemTime {
+    type Output = SystemTime;
+
+    fn sub(self, other: Duration) -> SystemTime {
+        self.checked_sub(other)
+            .expect("underflow while subtracting Duration from SystemTime")
+    }
+}
+
+impl Sub<SystemTime> for SystemTime {
+    type Output = Result<Duration, Duration>;
+
+    fn sub(self, other: SystemTime) -> Result<Duration, Duration> {
+        self.duration_since(other)
+    }
 }
 
 #[cfg(test)]
@@ -102,4 +145,59 @@ mod tests {
         let now = SystemTime::now();
         assert!(now.duration_since(UNIX_EPOCH).is_ok());
     }
+
+    #[test]
+    fn checked_add_and_sub_roundtrip() {
+        let start = SystemTime::from_unix_duration(Duration::from_secs(5));
+        let delta = Duration::from_secs(2);
+        let end = start.checked_add(delta).expect("must not overflow");
+        assert_eq!(end.as_unix_duration(), Duration::from_secs(7));
+        assert_eq!(end.checked_sub(delta), Some(start));
+    }
+
+    #[test]
+    fn checked_add_overflow_returns_none() {
+        let start = SystemTime::from_unix_duration(Duration::MAX);
+        assert_eq!(start.checked_add(Duration::from_nanos(1)), None);
+    }
+
+    #[test]
+    fn checked_sub_underflow_returns_none() {
+        let start = UNIX_EPOCH;
+        assert_eq!(start.checked_sub(Duration::from_nanos(1)), None);
+    }
+
+    #[test]
+    fn add_operator_works() {
+        let start = SystemTime::from_unix_duration(Duration::from_secs(5));
+        let end = start + Duration::from_secs(2);
+        assert_eq!(end.as_unix_duration(), Duration::from_secs(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow while adding Duration to SystemTime")]
+    fn add_operator_panics_on_overflow() {
+        let _ = SystemTime::from_unix_duration(Duration::MAX) + Duration::from_nanos(1);
+    }
+
+    #[test]
+    fn sub_operator_works() {
+        let end = SystemTime::from_unix_duration(Duration::from_secs(7));
+        let start = end - Duration::from_secs(2);
+        assert_eq!(start.as_unix_duration(), Duration::from_secs(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "underflow while subtracting Duration from SystemTime")]
+    fn sub_operator_panics_on_underflow() {
+        let _ = UNIX_EPOCH - Duration::from_nanos(1);
+    }
+
+    #[test]
+    fn sub_system_time_operator_matches_duration_since() {
+        let earlier = SystemTime::from_unix_duration(Duration::from_secs(10));
+        let later = SystemTime::from_unix_duration(Duration::from_secs(12));
+        assert_eq!(later - earlier, Ok(Duration::from_secs(2)));
+        assert_eq!(earlier - later, Err(Duration::from_secs(2)));
+    }
 }
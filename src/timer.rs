@@ -0,0 +1,93 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+
+use crate::once_static::OnceStatic;
+use crate::Instant;
+
+/// Hook that lets an installed time context schedule a wakeup at a future instant.
+///
+/// Implementing this is optional. When no driver is installed (or the driver
+/// declines a request), [`Timer`] falls back to busy-polling: it re-arms its
+/// waker on every poll until the target instant has passed.
+pub trait AlarmDriver: Send + Sync {
+    /// Arranges for `waker` to be woken at or after `at`.
+    ///
+    /// Returns `true` if the alarm was scheduled, or `false` if this driver
+    /// cannot currently service the request.
+    fn set_alarm(&self, at: Instant, waker: &Waker) -> bool;
+}
+
+/// Error returned when attempting to set the global alarm driver more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlobalAlarmDriverAlreadySet;
+
+static GLOBAL_ALARM_DRIVER: OnceStatic<&'static dyn AlarmDriver> = OnceStatic::new();
+
+/// Installs the global alarm driver used by [`Timer`] to avoid busy-polling.
+///
+/// This can be called only once for the process lifetime.
+pub fn set_global_alarm_driver(
+    driver: &'static dyn AlarmDriver,
+) -> Result<(), GlobalAlarmDriverAlreadySet> {
+    if GLOBAL_ALARM_DRIVER.set(driver) {
+        Ok(())
+    } else {
+        Err(GlobalAlarmDriverAlreadySet)
+    }
+}
+
+/// Returns the globally configured alarm driver if one was installed.
+pub fn global_alarm_driver() -> Option<&'static dyn AlarmDriver> {
+    GLOBAL_ALARM_DRIVER.get()
+}
+
+/// A future that resolves once [`Instant::now`] has reached a target instant.
+#[derive(Debug, Clone, Copy)]
+pub struct Timer {
+    at: Instant,
+}
+
+impl Timer {
+    /// Creates a timer that resolves once `at` has passed.
+    #[inline]
+    pub const fn at(at: Instant) -> Self {
+        Self { at }
+    }
+
+    /// Creates a timer that resolves after `duration` has elapsed from now.
+    #[inline]
+    pub fn after(duration: Duration) -> Self {
+        Self::at(Instant::now() + duration)
+    }
+}
+
+impl Future for Timer {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if Instant::now() >= self.at {
+            return Poll::Ready(());
+        }
+
+        if let Some(driver) = global_alarm_driver() {
+            if driver.set_alarm(self.at, cx.waker()) {
+                return Poll::Pending;
+            }
+        }
+
+        // No driver installed (or it declined); re-arm on every poll.
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Lets an executor `.await` a delay given as a [`Duration`].
+///
+/// Implement this for your executor's delay type; [`Timer::after`] is the
+/// natural building block for doing so.
+pub trait Delay {
+    /// Waits until `duration` has elapsed.
+    fn delay(&mut self, duration: Duration) -> impl Future<Output = ()>;
+}
@@ -0,0 +1,84 @@
+extern crate alloc;
+
+use core::cmp::Reverse;
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+
+use crate::once_static::OnceStatic;
+use crate::Instant;
+
+/// Identifies a scheduled entry in a [`TimerQueue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerId(pub u32);
+
+/// Source of "what time is it" plus "call me at this instant", for boards
+/// that drive timer callbacks from a hardware timer interrupt.
+pub trait TimerDriver: Send + Sync {
+    /// Arranges for `id` to become due at `at`.
+    fn schedule(&self, at: Instant, id: TimerId);
+
+    /// Returns the current monotonic instant as seen by this driver.
+    fn now(&self) -> Instant;
+}
+
+/// Error returned when attempting to set the global timer driver more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlobalTimerDriverAlreadySet;
+
+static GLOBAL_TIMER_DRIVER: OnceStatic<&'static dyn TimerDriver> = OnceStatic::new();
+
+/// Installs the global timer driver.
+///
+/// This can be called only once for the process lifetime.
+pub fn set_global_timer_driver(
+    driver: &'static dyn TimerDriver,
+) -> Result<(), GlobalTimerDriverAlreadySet> {
+    if GLOBAL_TIMER_DRIVER.set(driver) {
+        Ok(())
+    } else {
+        Err(GlobalTimerDriverAlreadySet)
+    }
+}
+
+/// Returns the globally configured timer driver if one was installed.
+pub fn global_timer_driver() -> Option<&'static dyn TimerDriver> {
+    GLOBAL_TIMER_DRIVER.get()
+}
+
+/// Software timer queue ordered by due instant.
+///
+/// A bare-metal timer ISR can drive this by calling [`TimerQueue::expire`]
+/// with the current time on every tick to pop all entries that are due.
+#[derive(Debug, Default)]
+pub struct TimerQueue {
+    heap: BinaryHeap<Reverse<(Instant, TimerId)>>,
+}
+
+impl TimerQueue {
+    /// Creates an empty timer queue.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Schedules `id` to become due at `at`.
+    pub fn schedule(&mut self, at: Instant, id: TimerId) {
+        self.heap.push(Reverse((at, id)));
+    }
+
+    /// Pops and returns every entry whose due instant is at or before `now`.
+    pub fn expire(&mut self, now: Instant) -> Vec<TimerId> {
+        let mut due = Vec::new();
+        while let Some(Reverse((at, _))) = self.heap.peek() {
+            if *at > now {
+                break;
+            }
+            let Reverse((_, id)) = self.heap.pop().expect("peek just confirmed an entry");
+            due.push(id);
+        }
+        due
+    }
+}
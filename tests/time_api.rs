@@ -2,8 +2,8 @@ use std::sync::OnceLock;
 use std::time::Duration;
 
 use universal_time::{
-    global_time_context, set_global_time_context, GlobalTimeContextAlreadySet, Instant,
-    MonotonicClock, SystemTime, TimeContext, WallClock, UNIX_EPOCH,
+    global_time_context, set_global_time_context, CivilDateTime, GlobalTimeContextAlreadySet,
+    Instant, MonotonicClock, SystemTime, TimeContext, WallClock, UNIX_EPOCH,
 };
 
 struct TestContext;
@@ -71,6 +71,60 @@ fn system_time_duration_since_backward() {
     assert_eq!(earlier.duration_since(later), Err(Duration::from_secs(2)));
 }
 
+#[test]
+fn system_time_checked_add_and_sub_roundtrip() {
+    let start = SystemTime::from_unix_duration(Duration::from_secs(5));
+    let delta = Duration::from_secs(2);
+    let end = start.checked_add(delta).expect("must not overflow");
+    assert_eq!(end.as_unix_duration(), Duration::from_secs(7));
+    assert_eq!(end.checked_sub(delta), Some(start));
+}
+
+#[test]
+fn system_time_checked_add_overflow_returns_none() {
+    let start = SystemTime::from_unix_duration(Duration::MAX);
+    assert_eq!(start.checked_add(Duration::from_nanos(1)), None);
+}
+
+#[test]
+fn system_time_checked_sub_underflow_returns_none() {
+    assert_eq!(UNIX_EPOCH.checked_sub(Duration::from_nanos(1)), None);
+}
+
+#[test]
+fn system_time_add_operator_works() {
+    let start = SystemTime::from_unix_duration(Duration::from_secs(5));
+    let end = start + Duration::from_secs(2);
+    assert_eq!(end.as_unix_duration(), Duration::from_secs(7));
+}
+
+#[test]
+#[should_panic(expected = "overflow while adding Duration to SystemTime")]
+fn system_time_add_operator_panics_on_overflow() {
+    let _ = SystemTime::from_unix_duration(Duration::MAX) + Duration::from_nanos(1);
+}
+
+#[test]
+fn system_time_sub_operator_works() {
+    let end = SystemTime::from_unix_duration(Duration::from_secs(7));
+    let start = end - Duration::from_secs(2);
+    assert_eq!(start.as_unix_duration(), Duration::from_secs(5));
+}
+
+#[test]
+#[should_panic(expected = "underflow while subtracting Duration from SystemTime")]
+fn system_time_sub_operator_panics_on_underflow() {
+    let _ = UNIX_EPOCH - Duration::from_nanos(1);
+}
+
+#[test]
+fn system_time_sub_system_time_operator_matches_duration_since() {
+    let earlier = SystemTime::from_unix_duration(Duration::from_secs(10));
+    let later = SystemTime::from_unix_duration(Duration::from_secs(12));
+    assert_eq!(later - earlier, Ok(Duration::from_secs(2)));
+    assert_eq!(earlier - later, Err(Duration::from_secs(2)));
+}
+
 #[test]
 fn instant_roundtrip_ticks() {
     let ticks = Duration::from_millis(42);
@@ -193,3 +247,28 @@ fn system_time_now_is_after_unix_epoch() {
     let now = SystemTime::now();
     assert!(now.duration_since(UNIX_EPOCH).is_ok());
 }
+
+#[test]
+fn civil_known_timestamp_breaks_down_correctly() {
+    // 2024-01-15T12:34:56Z
+    let time = SystemTime::from_unix_duration(Duration::from_secs(1_705_322_096));
+    let civil = time.to_civil_utc();
+    assert_eq!(
+        civil,
+        CivilDateTime {
+            year: 2024,
+            month: 1,
+            day: 15,
+            hour: 12,
+            minute: 34,
+            second: 56,
+            nanos: 0,
+        }
+    );
+}
+
+#[test]
+fn civil_roundtrips_through_civil_and_back() {
+    let time = SystemTime::from_unix_duration(Duration::new(1_705_322_096, 123_456_789));
+    assert_eq!(SystemTime::from_civil_utc(time.to_civil_utc()), time);
+}